@@ -0,0 +1,57 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    FloorDiv,
+    Mod,
+    Pow,
+    Concat,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Eq,
+    Ne,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnOp {
+    Neg,
+    Not,
+    Len,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Nil,
+    Bool(bool),
+    Int(u64),
+    Float(f64),
+    Str(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Literal(Literal),
+    Name(String),
+    Unary { op: UnOp, expr: Box<Expr> },
+    Binary { op: BinOp, lhs: Box<Expr>, rhs: Box<Expr> },
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmt {
+    Local { names: Vec<String>, values: Vec<Expr> },
+    Assign { targets: Vec<Expr>, values: Vec<Expr> },
+    If { arms: Vec<(Expr, Block)>, else_block: Option<Block> },
+    While { cond: Expr, body: Block },
+    NumericFor { var: String, start: Expr, stop: Expr, step: Option<Expr>, body: Block },
+    Function { name: String, params: Vec<String>, body: Block },
+    Return(Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Block(pub Vec<Stmt>);
@@ -1,11 +1,18 @@
 use std::time::SystemTime;
-use crate::parser::token::{TokenEnum};
+use crate::parser::token::TokenEnum;
+use crate::parser::Parser;
 
 mod parser;
 mod ast;
 
 fn main() {
     let now = SystemTime::now();
-    TokenEnum::try_lexer("./lua/test.lua").unwrap();
+    let mut buf = String::new();
+    let (tokens, errors) = TokenEnum::try_lexer("./lua/test.lua", &mut buf).unwrap();
+    for err in &errors {
+        eprintln!("{}", err);
+    }
+    let block = Parser::new(tokens).parse_block();
+    println!("{:?}", block);
     println!("{:?}", now.elapsed())
 }
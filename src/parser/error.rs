@@ -0,0 +1,68 @@
+use std::fmt;
+use logos::Span;
+use crate::parser::source_map::Position;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    InvalidNumber,
+    UnterminatedString,
+    UnknownToken,
+    InvalidIdentifier,
+    EndOfTokenStream,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ErrorKind::InvalidNumber => "invalid number",
+            ErrorKind::UnterminatedString => "unterminated string",
+            ErrorKind::UnknownToken => "unknown token",
+            ErrorKind::InvalidIdentifier => "invalid identifier",
+            ErrorKind::EndOfTokenStream => "unexpected end of token stream",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// A single lexer diagnostic, collected rather than printed so a whole file
+/// can be reported (or tested) at once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub span: Span,
+    pub pos: Position,
+    pub file_path: String,
+    pub slice: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}: {} '{}'", self.file_path, self.pos.line, self.pos.col, self.kind, self.slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_kind_display_matches_expected_messages() {
+        assert_eq!(ErrorKind::InvalidNumber.to_string(), "invalid number");
+        assert_eq!(ErrorKind::UnterminatedString.to_string(), "unterminated string");
+        assert_eq!(ErrorKind::UnknownToken.to_string(), "unknown token");
+        assert_eq!(ErrorKind::InvalidIdentifier.to_string(), "invalid identifier");
+        assert_eq!(ErrorKind::EndOfTokenStream.to_string(), "unexpected end of token stream");
+    }
+
+    #[test]
+    fn error_display_renders_file_position_and_slice() {
+        let err = Error {
+            kind: ErrorKind::UnknownToken,
+            span: 0..1,
+            pos: Position { line: 2, col: 3 },
+            file_path: "test.lua".to_string(),
+            slice: "@".to_string(),
+        };
+        assert_eq!(err.to_string(), "test.lua:2:3: unknown token '@'");
+    }
+}
@@ -0,0 +1,9 @@
+pub mod error;
+pub mod source_map;
+pub mod token;
+mod parser;
+
+pub use error::{Error, ErrorKind};
+pub use parser::Parser;
+pub use source_map::{Position, SourceMap};
+pub use token::{lex, lex_all, Token, TokenEnum};
@@ -0,0 +1,339 @@
+use crate::ast::{BinOp, Block, Expr, Literal, Stmt, UnOp};
+use crate::parser::token::TokenEnum;
+
+/// Binding power used for unary `-`/`not`, sitting between `..` and `^`.
+const UNARY_BP: u8 = 8;
+
+/// Consumes a flat `Vec<TokenEnum>` into the crate's `Block` AST, using
+/// precedence-climbing for expressions and plain recursive descent for
+/// statements.
+pub struct Parser<'src> {
+    tokens: Vec<TokenEnum<'src>>,
+    pos: usize,
+}
+
+impl<'src> Parser<'src> {
+    pub fn new(tokens: Vec<TokenEnum<'src>>) -> Self {
+        let tokens = tokens
+            .into_iter()
+            .filter(|t| !matches!(t, TokenEnum::Skip | TokenEnum::Line(_) | TokenEnum::Comment(_)))
+            .collect();
+        Self { tokens, pos: 0 }
+    }
+
+    pub fn parse_block(&mut self) -> Block {
+        let mut stmts = Vec::new();
+        while !self.at_block_end() {
+            if self.peek_is(|t| matches!(t, TokenEnum::Semicolon(_))) {
+                self.advance();
+                continue;
+            }
+            stmts.push(self.parse_statement());
+            if self.peek_is(|t| matches!(t, TokenEnum::Semicolon(_))) {
+                self.advance();
+            }
+        }
+        Block(stmts)
+    }
+
+    fn at_block_end(&self) -> bool {
+        match self.peek() {
+            None => true,
+            Some(TokenEnum::End(_)) | Some(TokenEnum::Else(_)) | Some(TokenEnum::Elseif(_)) => true,
+            _ => false,
+        }
+    }
+
+    fn parse_statement(&mut self) -> Stmt {
+        match self.peek() {
+            Some(TokenEnum::Local(_)) => self.parse_local(),
+            Some(TokenEnum::If(_)) => self.parse_if(),
+            Some(TokenEnum::While(_)) => self.parse_while(),
+            Some(TokenEnum::For(_)) => self.parse_for(),
+            Some(TokenEnum::Function(_)) => self.parse_function(),
+            Some(TokenEnum::Return(_)) => self.parse_return(),
+            _ => self.parse_assign(),
+        }
+    }
+
+    fn parse_local(&mut self) -> Stmt {
+        self.advance();
+        let mut names = vec![self.expect_name()];
+        while self.peek_is(|t| matches!(t, TokenEnum::Comma(_))) {
+            self.advance();
+            names.push(self.expect_name());
+        }
+        let values = if self.peek_is(|t| matches!(t, TokenEnum::Equal(_))) {
+            self.advance();
+            self.parse_expr_list()
+        } else {
+            Vec::new()
+        };
+        Stmt::Local { names, values }
+    }
+
+    fn parse_if(&mut self) -> Stmt {
+        self.advance();
+        let cond = self.parse_expr(0);
+        self.expect(|t| matches!(t, TokenEnum::Then(_)), "'then'");
+        let body = self.parse_block();
+        let mut arms = vec![(cond, body)];
+        while self.peek_is(|t| matches!(t, TokenEnum::Elseif(_))) {
+            self.advance();
+            let cond = self.parse_expr(0);
+            self.expect(|t| matches!(t, TokenEnum::Then(_)), "'then'");
+            arms.push((cond, self.parse_block()));
+        }
+        let else_block = if self.peek_is(|t| matches!(t, TokenEnum::Else(_))) {
+            self.advance();
+            Some(self.parse_block())
+        } else {
+            None
+        };
+        self.expect(|t| matches!(t, TokenEnum::End(_)), "'end'");
+        Stmt::If { arms, else_block }
+    }
+
+    fn parse_while(&mut self) -> Stmt {
+        self.advance();
+        let cond = self.parse_expr(0);
+        self.expect(|t| matches!(t, TokenEnum::Do(_)), "'do'");
+        let body = self.parse_block();
+        self.expect(|t| matches!(t, TokenEnum::End(_)), "'end'");
+        Stmt::While { cond, body }
+    }
+
+    fn parse_for(&mut self) -> Stmt {
+        self.advance();
+        let var = self.expect_name();
+        self.expect(|t| matches!(t, TokenEnum::Equal(_)), "'='");
+        let start = self.parse_expr(0);
+        self.expect(|t| matches!(t, TokenEnum::Comma(_)), "','");
+        let stop = self.parse_expr(0);
+        let step = if self.peek_is(|t| matches!(t, TokenEnum::Comma(_))) {
+            self.advance();
+            Some(self.parse_expr(0))
+        } else {
+            None
+        };
+        self.expect(|t| matches!(t, TokenEnum::Do(_)), "'do'");
+        let body = self.parse_block();
+        self.expect(|t| matches!(t, TokenEnum::End(_)), "'end'");
+        Stmt::NumericFor { var, start, stop, step, body }
+    }
+
+    fn parse_function(&mut self) -> Stmt {
+        self.advance();
+        let name = self.expect_name();
+        self.expect(|t| matches!(t, TokenEnum::ParenthesesLeft(_)), "'('");
+        let mut params = Vec::new();
+        if !self.peek_is(|t| matches!(t, TokenEnum::ParenthesesRight(_))) {
+            params.push(self.expect_name());
+            while self.peek_is(|t| matches!(t, TokenEnum::Comma(_))) {
+                self.advance();
+                params.push(self.expect_name());
+            }
+        }
+        self.expect(|t| matches!(t, TokenEnum::ParenthesesRight(_)), "')'");
+        let body = self.parse_block();
+        self.expect(|t| matches!(t, TokenEnum::End(_)), "'end'");
+        Stmt::Function { name, params, body }
+    }
+
+    fn parse_return(&mut self) -> Stmt {
+        self.advance();
+        let values = if self.at_block_end() || self.peek_is(|t| matches!(t, TokenEnum::Semicolon(_))) {
+            Vec::new()
+        } else {
+            self.parse_expr_list()
+        };
+        Stmt::Return(values)
+    }
+
+    fn parse_assign(&mut self) -> Stmt {
+        let mut targets = vec![self.parse_expr(0)];
+        while self.peek_is(|t| matches!(t, TokenEnum::Comma(_))) {
+            self.advance();
+            targets.push(self.parse_expr(0));
+        }
+        self.expect(|t| matches!(t, TokenEnum::Equal(_)), "'='");
+        let values = self.parse_expr_list();
+        Stmt::Assign { targets, values }
+    }
+
+    fn parse_expr_list(&mut self) -> Vec<Expr> {
+        let mut values = vec![self.parse_expr(0)];
+        while self.peek_is(|t| matches!(t, TokenEnum::Comma(_))) {
+            self.advance();
+            values.push(self.parse_expr(0));
+        }
+        values
+    }
+
+    /// Precedence-climbing core: parses a prefix/primary expression, then
+    /// keeps absorbing binary operators whose left binding power is at
+    /// least `min_bp`.
+    pub fn parse_expr(&mut self, min_bp: u8) -> Expr {
+        let mut lhs = self.parse_unary_or_primary();
+        while let Some(bp) = self.peek().and_then(TokenEnum::precedence) {
+            if bp < min_bp {
+                break;
+            }
+            let op_tok = self.advance().expect("precedence() implies a token is present");
+            let op = bin_op_of(op_tok);
+            let right_bp = if op_tok.is_right_assoc() { bp } else { bp + 1 };
+            let rhs = self.parse_expr(right_bp);
+            lhs = Expr::Binary { op, lhs: Box::new(lhs), rhs: Box::new(rhs) };
+        }
+        lhs
+    }
+
+    fn parse_unary_or_primary(&mut self) -> Expr {
+        match self.peek() {
+            Some(TokenEnum::Sub(_)) => {
+                self.advance();
+                Expr::Unary { op: UnOp::Neg, expr: Box::new(self.parse_expr(UNARY_BP)) }
+            }
+            Some(TokenEnum::Not(_)) => {
+                self.advance();
+                Expr::Unary { op: UnOp::Not, expr: Box::new(self.parse_expr(UNARY_BP)) }
+            }
+            Some(TokenEnum::Len(_)) => {
+                self.advance();
+                Expr::Unary { op: UnOp::Len, expr: Box::new(self.parse_expr(UNARY_BP)) }
+            }
+            _ => self.parse_primary(),
+        }
+    }
+
+    fn parse_primary(&mut self) -> Expr {
+        let tok = self.advance().expect("unexpected end of token stream");
+        match tok {
+            TokenEnum::Int(t) => Expr::Literal(Literal::Int(t.v)),
+            TokenEnum::Float(t) => Expr::Literal(Literal::Float(t.v)),
+            TokenEnum::Bool(t) => Expr::Literal(Literal::Bool(t.v)),
+            TokenEnum::Nil(_) => Expr::Literal(Literal::Nil),
+            TokenEnum::QuotedString(t) => Expr::Literal(Literal::Str(String::from_utf8_lossy(&t.v).into_owned())),
+            TokenEnum::LongString(t) => Expr::Literal(Literal::Str(t.v.clone())),
+            TokenEnum::Name(t) => Expr::Name(t.v.to_string()),
+            TokenEnum::ParenthesesLeft(_) => {
+                let inner = self.parse_expr(0);
+                self.expect(|t| matches!(t, TokenEnum::ParenthesesRight(_)), "')'");
+                inner
+            }
+            other => panic!("unexpected token in expression: {:?}", other),
+        }
+    }
+
+    fn peek(&self) -> Option<&TokenEnum<'src>> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_is(&self, pred: impl Fn(&TokenEnum<'src>) -> bool) -> bool {
+        self.peek().map_or(false, pred)
+    }
+
+    fn advance(&mut self) -> Option<&TokenEnum<'src>> {
+        let tok = self.tokens.get(self.pos);
+        if tok.is_some() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn expect(&mut self, pred: impl Fn(&TokenEnum<'src>) -> bool, what: &str) {
+        match self.advance() {
+            Some(tok) if pred(tok) => {}
+            other => panic!("expected {}, found {:?}", what, other),
+        }
+    }
+
+    fn expect_name(&mut self) -> String {
+        match self.advance() {
+            Some(TokenEnum::Name(t)) => t.v.to_string(),
+            other => panic!("expected identifier, found {:?}", other),
+        }
+    }
+}
+
+fn bin_op_of(tok: &TokenEnum) -> BinOp {
+    match tok {
+        TokenEnum::Or(_) => BinOp::Or,
+        TokenEnum::And(_) => BinOp::And,
+        TokenEnum::Lt(_) => BinOp::Lt,
+        TokenEnum::Gt(_) => BinOp::Gt,
+        TokenEnum::Le(_) => BinOp::Le,
+        TokenEnum::Ge(_) => BinOp::Ge,
+        TokenEnum::DoubleEqual(_) => BinOp::Eq,
+        TokenEnum::NotEqual(_) => BinOp::Ne,
+        TokenEnum::DoubleDot(_) => BinOp::Concat,
+        TokenEnum::Plus(_) => BinOp::Add,
+        TokenEnum::Sub(_) => BinOp::Sub,
+        TokenEnum::Mul(_) => BinOp::Mul,
+        TokenEnum::Div(_) => BinOp::Div,
+        TokenEnum::DivToInt(_) => BinOp::FloorDiv,
+        TokenEnum::Mod(_) => BinOp::Mod,
+        TokenEnum::Pow(_) => BinOp::Pow,
+        _ => unreachable!("precedence() guarantees this is a binary operator"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::token::lex_all;
+
+    fn parse(src: &str) -> Block {
+        let tokens = lex_all(src).into_iter().map(|r| r.expect("valid token")).collect();
+        Parser::new(tokens).parse_block()
+    }
+
+    #[test]
+    fn parses_local_assignment_with_arithmetic() {
+        let block = parse("local x = 1 + 2 * 3");
+        match &block.0[..] {
+            [Stmt::Local { names, values }] => {
+                assert_eq!(names, &["x"]);
+                assert!(matches!(&values[..], [Expr::Binary { op: BinOp::Add, .. }]));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_comparison_operators_without_panicking() {
+        let block = parse("x = a <= b");
+        match &block.0[..] {
+            [Stmt::Assign { values, .. }] => {
+                assert!(matches!(&values[..], [Expr::Binary { op: BinOp::Le, .. }]));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pow_is_right_associative() {
+        // `2 ^ 3 ^ 2` must parse as `2 ^ (3 ^ 2)`.
+        let block = parse("x = 2 ^ 3 ^ 2");
+        match &block.0[..] {
+            [Stmt::Assign { values, .. }] => match &values[..] {
+                [Expr::Binary { op: BinOp::Pow, rhs, .. }] => {
+                    assert!(matches!(**rhs, Expr::Binary { op: BinOp::Pow, .. }));
+                }
+                other => panic!("unexpected expr: {:?}", other),
+            },
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_length_unary_operator() {
+        let block = parse("x = #t");
+        match &block.0[..] {
+            [Stmt::Assign { values, .. }] => {
+                assert!(matches!(&values[..], [Expr::Unary { op: UnOp::Len, .. }]));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+}
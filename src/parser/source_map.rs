@@ -0,0 +1,58 @@
+use std::ops::Range;
+
+/// A 1-indexed line/column pair, as shown to users rather than stored as a
+/// raw byte offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub col: usize,
+}
+
+/// Translates byte offsets in a source string back into `Position`s after
+/// the fact, for diagnostics that only have a stored span to work with
+/// (rather than live lexer state like `TokenExtras` tracks as it scans).
+pub struct SourceMap {
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+        Self { line_starts }
+    }
+
+    pub fn position(&self, offset: usize) -> Position {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(exact) => exact,
+            Err(insertion) => insertion - 1,
+        };
+        Position { line: line + 1, col: offset - self.line_starts[line] + 1 }
+    }
+
+    pub fn range(&self, span: Range<usize>) -> Range<Position> {
+        self.position(span.start)..self.position(span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_resolves_offsets_across_multiple_lines() {
+        let map = SourceMap::new("ab\ncd\nefg");
+        assert_eq!(map.position(0), Position { line: 1, col: 1 });
+        assert_eq!(map.position(2), Position { line: 1, col: 3 });
+        assert_eq!(map.position(3), Position { line: 2, col: 1 });
+        assert_eq!(map.position(5), Position { line: 2, col: 3 });
+        assert_eq!(map.position(6), Position { line: 3, col: 1 });
+        assert_eq!(map.position(8), Position { line: 3, col: 3 });
+    }
+
+    #[test]
+    fn range_resolves_both_ends_of_a_span() {
+        let map = SourceMap::new("ab\ncd");
+        assert_eq!(map.range(3..5), Position { line: 2, col: 1 }..Position { line: 2, col: 3 });
+    }
+}
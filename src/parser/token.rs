@@ -4,6 +4,8 @@ use std::io::Read;
 use std::ops::Range;
 use std::str::FromStr;
 use logos::{Lexer, Logos, Span};
+use crate::parser::error::{Error, ErrorKind};
+use crate::parser::source_map::Position;
 
 pub struct TokenExtras {
     line_breaks: usize,
@@ -11,12 +13,26 @@ pub struct TokenExtras {
     file_path: String,
     before_token_is_separate: bool,
     before_token_start: usize,
+    errors: Vec<Error>,
 }
 
 impl TokenExtras {
-    pub fn println_err(&self, lex: &Lexer<TokenEnum>, span: Range<usize>, reason: &str) {
-        let x = &lex.source()[span];
-        println!("{}:{}: {} '{}'", self.file_path, self.line_breaks, reason, x)
+    /// Cheap line/col lookup using the running counters kept as we scan,
+    /// rather than a full `SourceMap` pass. Only valid for offsets on or
+    /// after the current line.
+    fn position(&self, offset: usize) -> Position {
+        Position { line: self.line_breaks, col: offset.saturating_sub(self.line_start) + 1 }
+    }
+
+    fn push_error(&mut self, span: Range<usize>, slice: &str, kind: ErrorKind) {
+        let pos = self.position(span.start);
+        self.errors.push(Error {
+            kind,
+            span,
+            pos,
+            file_path: self.file_path.clone(),
+            slice: slice.to_string(),
+        });
     }
 }
 
@@ -28,19 +44,22 @@ impl Default for TokenExtras {
             file_path: "".to_string(),
             before_token_is_separate: true,
             before_token_start: 0,
+            errors: Vec::new(),
         }
     }
 }
 
 #[derive(Logos, Debug)]
 #[logos(extras = TokenExtras)]
-pub enum TokenEnum {
+pub enum TokenEnum<'src> {
     #[regex(r"[ \t]+")]
     Skip,
     #[regex(r"(\r\n)|[\n\f\r]", line)]
     Line(Token<String>),
-    #[regex(r"--[^\n\f\r]*", string_lexer)]
-    Comment(Token<String>),
+    #[regex(r"--[^\n\f\r]*", comment_lexer)]
+    Comment(Token<&'src str>),
+    #[token("==", empty)]
+    DoubleEqual(Token<()>),
     #[token("=", empty)]
     Equal(Token<()>),
     #[token("+", empty)]
@@ -53,22 +72,58 @@ pub enum TokenEnum {
     Div(Token<()>),
     #[token("//", empty)]
     DivToInt(Token<()>),
+    #[token("%", empty)]
+    Mod(Token<()>),
+    #[token("^", empty)]
+    Pow(Token<()>),
+    #[token("#", empty)]
+    Len(Token<()>),
+    #[token("&", empty)]
+    BitAnd(Token<()>),
+    #[token("|", empty)]
+    BitOr(Token<()>),
+    #[token("~=", empty)]
+    NotEqual(Token<()>),
+    #[token("~", empty)]
+    Tilde(Token<()>),
+    #[token("<<", empty)]
+    Shl(Token<()>),
+    #[token(">>", empty)]
+    Shr(Token<()>),
     #[token(";", empty)]
     Semicolon(Token<()>),
     #[token(",", empty)]
     Comma(Token<()>),
+    #[token("...", empty)]
+    Ellipsis(Token<()>),
     #[token("..", empty)]
     DoubleDot(Token<()>),
     #[token(".", empty)]
     Dot(Token<()>),
+    #[token(">=", empty)]
+    Ge(Token<()>),
     #[token(">", empty)]
     Gt(Token<()>),
+    #[token("<=", empty)]
+    Le(Token<()>),
     #[token("<", empty)]
     Lt(Token<()>),
     #[token("(", empty)]
     ParenthesesLeft(Token<()>),
     #[token(")", empty)]
     ParenthesesRight(Token<()>),
+    #[token("{", empty)]
+    BraceLeft(Token<()>),
+    #[token("}", empty)]
+    BraceRight(Token<()>),
+    #[token("[", empty)]
+    BracketLeft(Token<()>),
+    #[token("]", empty)]
+    BracketRight(Token<()>),
+    #[token("::", empty)]
+    DoubleColon(Token<()>),
+    #[token(":", empty)]
+    Colon(Token<()>),
     #[token("function", empty)]
     Function(Token<()>),
     #[token("end", empty)]
@@ -101,8 +156,16 @@ pub enum TokenEnum {
     And(Token<()>),
     #[token("or", empty)]
     Or(Token<()>),
-    #[regex(r"[_a-zA-Z][_0-9a-zA-Z]*", parse_lexer)]
-    Name(Token<String>),
+    #[token("not", empty)]
+    Not(Token<()>),
+    #[token("nil", empty)]
+    Nil(Token<()>),
+    #[token("return", empty)]
+    Return(Token<()>),
+    #[token("in", empty)]
+    In(Token<()>),
+    #[regex(r"[_a-zA-Z][_0-9a-zA-Z]*", name_lexer)]
+    Name(Token<&'src str>),
     #[regex("(0x)?[0-9]+", u64_lexer)]
     Int(Token<u64>),
     #[regex(r"([0-9]*\.[0-9]+([eE][+-]?[0-9]+)?)|([0-9]+\.[0-9]*([eE][+-]?[0-9]+)?)|([0-9]+[eE][+-]?[0-9]+)", parse_lexer)]
@@ -110,30 +173,52 @@ pub enum TokenEnum {
     #[token("true", parse_lexer)]
     #[token("false", parse_lexer)]
     Bool(Token<bool>),
-    #[regex(r#""([^"\\]|\\.|"")*""#, string_lexer)]
-    #[regex(r#"'([^'\\]|\\.|'')*'"#, string_lexer)]
-    QuotedString(Token<String>),
+    #[regex(r#""([^"\\]|\\.|"")*""#, quoted_string_lexer)]
+    #[regex(r#"'([^'\\]|\\.|'')*'"#, quoted_string_lexer)]
+    QuotedString(Token<Vec<u8>>),
+    #[regex(r"\[=*\[", long_string_lexer)]
+    LongString(Token<String>),
 }
 
-impl TokenEnum {
+impl<'src> TokenEnum<'src> {
     pub fn is_separate(&self) -> bool {
         match self {
             TokenEnum::Skip
             | TokenEnum::Line(_)
             | TokenEnum::Comment(_)
             | TokenEnum::Equal(_)
+            | TokenEnum::DoubleEqual(_)
+            | TokenEnum::NotEqual(_)
             | TokenEnum::Plus(_)
             | TokenEnum::Semicolon(_)
             | TokenEnum::Comma(_)
+            | TokenEnum::Ellipsis(_)
             | TokenEnum::DoubleDot(_)
             | TokenEnum::ParenthesesLeft(_)
+            | TokenEnum::Ge(_)
             | TokenEnum::Gt(_)
+            | TokenEnum::Le(_)
             | TokenEnum::Lt(_)
             | TokenEnum::Sub(_)
             | TokenEnum::Mul(_)
             | TokenEnum::Div(_)
             | TokenEnum::DivToInt(_)
+            | TokenEnum::Mod(_)
+            | TokenEnum::Pow(_)
+            | TokenEnum::Len(_)
+            | TokenEnum::BitAnd(_)
+            | TokenEnum::BitOr(_)
+            | TokenEnum::Tilde(_)
+            | TokenEnum::Shl(_)
+            | TokenEnum::Shr(_)
+            | TokenEnum::BraceLeft(_)
+            | TokenEnum::BraceRight(_)
+            | TokenEnum::BracketLeft(_)
+            | TokenEnum::BracketRight(_)
+            | TokenEnum::Colon(_)
+            | TokenEnum::DoubleColon(_)
             | TokenEnum::QuotedString(_)
+            | TokenEnum::LongString(_)
             | TokenEnum::ParenthesesRight(_) => true,
             _ => false,
         }
@@ -141,98 +226,354 @@ impl TokenEnum {
 }
 
 
+impl<'src> TokenEnum<'src> {
+    /// Binding power of a binary operator token, per Lua's precedence table
+    /// (low to high: `or`, `and`, comparisons, `..`, `+ -`, `* / // %`, `^`).
+    /// Returns `None` for tokens that are not binary operators.
+    pub fn precedence(&self) -> Option<u8> {
+        match self {
+            TokenEnum::Or(_) => Some(1),
+            TokenEnum::And(_) => Some(2),
+            TokenEnum::Lt(_) | TokenEnum::Gt(_) | TokenEnum::Le(_) | TokenEnum::Ge(_)
+            | TokenEnum::DoubleEqual(_) | TokenEnum::NotEqual(_) => Some(3),
+            TokenEnum::DoubleDot(_) => Some(4),
+            TokenEnum::Plus(_) | TokenEnum::Sub(_) => Some(5),
+            TokenEnum::Mul(_) | TokenEnum::Div(_) | TokenEnum::DivToInt(_) | TokenEnum::Mod(_) => Some(6),
+            TokenEnum::Pow(_) => Some(10),
+            _ => None,
+        }
+    }
+
+    /// `..` and `^` bind their right-hand side at the same level instead of
+    /// level+1, making them right-associative.
+    pub fn is_right_assoc(&self) -> bool {
+        matches!(self, TokenEnum::DoubleDot(_) | TokenEnum::Pow(_))
+    }
+}
+
 #[derive(Debug)]
 pub struct Token<T> {
-    pub line: usize,
     pub span: Span,
+    pub start: Position,
+    pub end: Position,
     pub v: T,
 }
 
 
 impl<T> Token<T> {
     #[inline]
-    fn new(v: T, span: Span, line: usize) -> Self {
+    fn new(v: T, span: Span, start: Position, end: Position) -> Self {
         Self {
-            line,
             span,
+            start,
+            end,
             v,
         }
     }
 }
 
+/// Builds a `Token` by reading the current token's span off `lex` and
+/// resolving its start/end positions through `lex.extras`.
 #[inline]
-fn string_lexer(lex: &mut Lexer<TokenEnum>) -> Option<Token<String>> {
+fn token_at<'src, T>(lex: &Lexer<'src, TokenEnum<'src>>, v: T) -> Token<T> {
     let span = lex.span();
-    let x = lex.slice();
-    x.as_bytes().
-    Some(Token::new(x[..x.len() - 1].replace("\\", ""), span, lex.extras.line_breaks))
+    let start = lex.extras.position(span.start);
+    let end = lex.extras.position(span.end);
+    Token::new(v, span, start, end)
 }
 
-fn u64_lexer(lex: &mut Lexer<TokenEnum>) -> Option<Token<u64>> {
+#[inline]
+fn comment_lexer<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<&'src str>> {
+    let text = &lex.slice()[2..];
+    Some(token_at(lex, text))
+}
+
+#[inline]
+fn name_lexer<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<&'src str>> {
+    Some(token_at(lex, lex.slice()))
+}
+
+/// Decodes a quoted `"..."`/`'...'` literal: escapes are interpreted, the
+/// surrounding quotes are dropped. Kept as raw bytes (Lua strings are byte
+/// strings, not necessarily UTF-8) rather than converted to `String` here;
+/// conversion happens at the AST boundary in `parser.rs`.
+fn quoted_string_lexer<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<Vec<u8>>> {
+    let raw = lex.slice();
+    let body = &raw[1..raw.len() - 1];
+    match decode_escapes(body) {
+        Ok(decoded) => Some(token_at(lex, decoded)),
+        Err(kind) => record_error(lex, kind),
+    }
+}
+
+/// Lua strings are byte strings, so `\xXX` and `\ddd` escapes decode to a raw
+/// byte rather than a Unicode scalar — decoding into `Vec<u8>` keeps a `\200`
+/// as the single byte `200` instead of re-encoding it as multi-byte UTF-8.
+/// The caller turns the result back into a `String` at the use boundary.
+fn decode_escapes(body: &str) -> Result<Vec<u8>, ErrorKind> {
+    let mut out = Vec::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buf = [0u8; 4];
+            out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push(b'\n'),
+            Some('t') => out.push(b'\t'),
+            Some('r') => out.push(b'\r'),
+            Some('a') => out.push(0x07),
+            Some('b') => out.push(0x08),
+            Some('f') => out.push(0x0c),
+            Some('v') => out.push(0x0b),
+            Some('\\') => out.push(b'\\'),
+            Some('"') => out.push(b'"'),
+            Some('\'') => out.push(b'\''),
+            Some('\n') => out.push(b'\n'),
+            Some('z') => {
+                while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+                let byte = u8::from_str_radix(&hex, 16).map_err(|_| ErrorKind::UnterminatedString)?;
+                out.push(byte);
+            }
+            Some('u') => {
+                if chars.next() != Some('{') {
+                    return Err(ErrorKind::UnterminatedString);
+                }
+                let mut hex = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => hex.push(c),
+                        None => return Err(ErrorKind::EndOfTokenStream),
+                    }
+                }
+                let code = u32::from_str_radix(&hex, 16).map_err(|_| ErrorKind::UnterminatedString)?;
+                let ch = char::from_u32(code).ok_or(ErrorKind::UnterminatedString)?;
+                let mut buf = [0u8; 4];
+                out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            }
+            Some(d) if d.is_ascii_digit() => {
+                let mut digits = String::new();
+                digits.push(d);
+                while digits.len() < 3 {
+                    match chars.peek() {
+                        Some(c) if c.is_ascii_digit() => digits.push(*c),
+                        _ => break,
+                    }
+                    chars.next();
+                }
+                let byte: u32 = digits.parse().map_err(|_| ErrorKind::UnterminatedString)?;
+                out.push(u8::try_from(byte).map_err(|_| ErrorKind::InvalidNumber)?);
+            }
+            None => return Err(ErrorKind::EndOfTokenStream),
+            _ => return Err(ErrorKind::UnterminatedString),
+        }
+    }
+    Ok(out)
+}
+
+/// Long-bracket literals (`[[ ... ]]`, `[==[ ... ]==]`) do no escape
+/// processing and may span lines; the level of `=` signs must match on
+/// both ends, which regex alone can't enforce, so we scan for the closer
+/// by hand and `bump` past it.
+fn long_string_lexer<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<String>> {
+    let opener_len = lex.slice().len();
+    let body_start = lex.span().start + opener_len;
+    let level = opener_len - 2;
+    let closer = format!("]{}]", "=".repeat(level));
+    let remainder = lex.remainder();
+    match remainder.find(&closer) {
+        Some(idx) => {
+            let body = remainder[..idx].to_string();
+            lex.bump(idx + closer.len());
+            let start = lex.extras.position(lex.span().start);
+            if let Some(last_newline) = body.rfind('\n') {
+                lex.extras.line_breaks += body.matches('\n').count();
+                lex.extras.line_start = body_start + last_newline + 1;
+            }
+            let end = lex.extras.position(lex.span().end);
+            Some(Token::new(body, lex.span(), start, end))
+        }
+        None => record_error(lex, ErrorKind::UnterminatedString),
+    }
+}
+
+fn u64_lexer<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<u64>> {
     let mut x = lex.slice();
     let radix = if x.starts_with("0x") {
         x = &x[2..];
         16
     } else { 10 };
     match u64::from_str_radix(x, radix) {
-        Ok(v) => Some(Token::new(v, lex.span(), lex.extras.line_breaks)),
-        Err(err) => print_err(lex, err.to_string().as_str())
+        Ok(v) => Some(token_at(lex, v)),
+        Err(_) => record_error(lex, ErrorKind::InvalidNumber)
     }
 }
 
-fn parse_lexer<T>(lex: &mut Lexer<TokenEnum>) -> Option<Token<T>>
+fn parse_lexer<'src, T>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<T>>
     where <T as FromStr>::Err: std::fmt::Display, T: FromStr {
     match lex.slice().parse() {
-        Ok(v) => Some(Token::new(v, lex.span(), lex.extras.line_breaks)),
-        Err(err) => { print_err(lex, &err.to_string()) }
-    }
-}
-
-impl TokenEnum {
-    pub fn try_lexer(lua_path: &str) -> anyhow::Result<usize> {
-        let mut content = String::new();
-        File::open(lua_path)?.read_to_string(&mut content)?;
-        let mut lex = Self::lexer(content.as_str());
-        lex.extras.file_path = lua_path.to_string();
-        let mut token_list: Vec<TokenEnum> = Vec::new();
-        while let Some(token) = lex.next() {
-            println!("{:?}", token);
-            match token {
-                Ok(ok) => {
-                    on_token(&mut lex, &ok);
-                    token_list.push(ok);
+        Ok(v) => Some(token_at(lex, v)),
+        Err(_) => record_error(lex, ErrorKind::InvalidNumber),
+    }
+}
+
+/// Lexes `input` without touching the filesystem, borrowing `Name`/`Comment`
+/// slices straight out of it instead of allocating a `String` per token.
+/// Suitable for REPL input or editor buffers, not just whole files.
+pub fn lex<'src>(input: &'src str) -> impl Iterator<Item = Result<TokenEnum<'src>, Error>> + 'src {
+    let mut lexer = TokenEnum::lexer(input);
+    let mut pending_ok: Option<TokenEnum<'src>> = None;
+    std::iter::from_fn(move || {
+        if let Some(tok) = pending_ok.take() {
+            return Some(Ok(tok));
+        }
+        let before = lexer.extras.errors.len();
+        let token = lexer.next()?;
+        match token {
+            Ok(ok) => {
+                on_token(&mut lexer, &ok);
+                if lexer.extras.errors.len() > before {
+                    pending_ok = Some(ok);
+                    Some(Err(lexer.extras.errors.pop().expect("just grew")))
+                } else {
+                    Some(Ok(ok))
                 }
-                Err(err) => {
-                    println!("{:?}", err);
+            }
+            Err(_) => {
+                // A callback that rejected its slice has already recorded a
+                // more specific error; only an unmatched slice is unknown.
+                if lexer.extras.errors.len() == before {
+                    record_error::<()>(&mut lexer, ErrorKind::UnknownToken);
                 }
+                Some(Err(lexer.extras.errors.pop().expect("an error was just recorded")))
             }
         }
-        Ok(lex.count())
+    })
+}
+
+/// Convenience wrapper around [`lex`] that drains the iterator into a `Vec`.
+pub fn lex_all(input: &str) -> Vec<Result<TokenEnum<'_>, Error>> {
+    lex(input).collect()
+}
+
+impl<'src> TokenEnum<'src> {
+    /// Reads `lua_path` into `buf` and lexes it, returning every token
+    /// alongside every diagnostic collected along the way. `buf` must
+    /// outlive the returned tokens since `Name`/`Comment` borrow from it.
+    pub fn try_lexer(lua_path: &str, buf: &'src mut String) -> anyhow::Result<(Vec<TokenEnum<'src>>, Vec<Error>)> {
+        File::open(lua_path)?.read_to_string(buf)?;
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+        for result in lex(buf.as_str()) {
+            match result {
+                Ok(tok) => tokens.push(tok),
+                Err(mut err) => {
+                    err.file_path = lua_path.to_string();
+                    errors.push(err);
+                }
+            }
+        }
+        Ok((tokens, errors))
     }
 }
 
 #[inline]
-fn print_err<T>(lex: &Lexer<TokenEnum>, err: &str) -> Option<Token<T>> {
-    lex.extras.println_err(lex, lex.span(), err);
+fn record_error<'src, T>(lex: &mut Lexer<'src, TokenEnum<'src>>, kind: ErrorKind) -> Option<Token<T>> {
+    let span = lex.span();
+    let slice = lex.slice().to_string();
+    lex.extras.push_error(span, &slice, kind);
     None
 }
 
 
 #[inline]
-fn line(lex: &mut Lexer<TokenEnum>) -> Option<usize> {
+fn line<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<String>> {
+    let span = lex.span();
+    let start = lex.extras.position(span.start);
     lex.extras.line_breaks += 1;
-    lex.extras.line_start = lex.span().start;
-    Some(lex.extras.line_breaks)
+    lex.extras.line_start = span.end;
+    let end = lex.extras.position(span.end);
+    Some(Token::new(lex.slice().to_string(), span, start, end))
 }
 
-fn on_token(lex: &mut Lexer<TokenEnum>, token: &TokenEnum) {
+fn on_token<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>, token: &TokenEnum<'src>) {
     if !token.is_separate() && !lex.extras.before_token_is_separate {
-        lex.extras.println_err(lex, lex.extras.before_token_start..lex.span().end, "unknown token")
+        let span = lex.extras.before_token_start..lex.span().end;
+        let slice = lex.source()[span.clone()].to_string();
+        // A `Name` glued directly onto the previous token (e.g. `123abc`) is
+        // a malformed identifier boundary rather than a wholly unknown token.
+        let kind = if matches!(token, TokenEnum::Name(_)) {
+            ErrorKind::InvalidIdentifier
+        } else {
+            ErrorKind::UnknownToken
+        };
+        lex.extras.push_error(span, &slice, kind);
     }
     lex.extras.before_token_is_separate = token.is_separate();
     lex.extras.before_token_start = lex.span().start;
 }
 
-fn empty(lex: &mut Lexer<TokenEnum>) -> Option<Token<()>> {
-    Some(Token::new((), lex.span(), lex.extras.line_breaks))
-}
\ No newline at end of file
+fn empty<'src>(lex: &mut Lexer<'src, TokenEnum<'src>>) -> Option<Token<()>> {
+    Some(token_at(lex, ()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_escapes_handles_named_escapes() {
+        assert_eq!(decode_escapes("\\n\\t\\r\\\\\\\"\\'").unwrap(), b"\n\t\r\\\"'");
+    }
+
+    #[test]
+    fn decode_escapes_hex_and_decimal_are_raw_bytes_not_codepoints() {
+        assert_eq!(decode_escapes(r"\x48\x49").unwrap(), b"HI");
+        assert_eq!(decode_escapes(r"\200").unwrap(), vec![200u8]);
+    }
+
+    #[test]
+    fn decode_escapes_rejects_out_of_range_decimal_escape() {
+        assert_eq!(decode_escapes(r"\888").unwrap_err(), ErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn decode_escapes_unicode_escape_decodes_codepoint() {
+        assert_eq!(decode_escapes(r"\u{48}").unwrap(), b"H");
+    }
+
+    #[test]
+    fn decode_escapes_z_skips_following_whitespace() {
+        assert_eq!(decode_escapes("a\\z   \n  b").unwrap(), b"ab");
+    }
+
+    #[test]
+    fn lex_all_reads_long_bracket_string_spanning_lines() {
+        let tokens: Vec<_> = lex_all("[==[line one\nline two]==]")
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect();
+        match tokens.as_slice() {
+            [TokenEnum::LongString(t)] => assert_eq!(t.v, "line one\nline two"),
+            other => panic!("expected a single LongString token, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn precedence_orders_lua_operators_correctly() {
+        assert!(TokenEnum::Or(Token::new((), 0..0, Position { line: 1, col: 1 }, Position { line: 1, col: 1 })).precedence()
+            < TokenEnum::And(Token::new((), 0..0, Position { line: 1, col: 1 }, Position { line: 1, col: 1 })).precedence());
+        assert_eq!(
+            TokenEnum::Lt(Token::new((), 0..0, Position { line: 1, col: 1 }, Position { line: 1, col: 1 })).precedence(),
+            TokenEnum::Le(Token::new((), 0..0, Position { line: 1, col: 1 }, Position { line: 1, col: 1 })).precedence(),
+        );
+        assert!(TokenEnum::Pow(Token::new((), 0..0, Position { line: 1, col: 1 }, Position { line: 1, col: 1 })).is_right_assoc());
+    }
+}